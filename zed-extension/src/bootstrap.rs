@@ -0,0 +1,135 @@
+use std::fs;
+use std::path::Path;
+
+use zed_extension_api::{self as zed, LanguageServerId, Result};
+
+const GITHUB_REPO: &str = "kilocode/kilocode";
+const SIDECAR_ENTRY: &str = "server.js";
+
+/// Ensures the Node sidecar bundle for the current platform is present
+/// under `work_dir`, downloading and unpacking the latest GitHub release
+/// if missing, and returns the path to its entry point (`server.js`).
+///
+/// Downloading and archive extraction happen on the host via the
+/// `zed_extension_api` release helpers, not inside the extension's wasm
+/// sandbox, which has no socket or process access of its own.
+pub fn ensure_sidecar_bundle(
+    language_server_id: &LanguageServerId,
+    work_dir: &Path,
+) -> Result<String> {
+    zed::set_language_server_installation_status(
+        language_server_id,
+        &zed::LanguageServerInstallationStatus::CheckingForUpdate,
+    );
+
+    let release = zed::latest_github_release(
+        GITHUB_REPO,
+        zed::GithubReleaseOptions {
+            require_assets: true,
+            pre_release: false,
+        },
+    )?;
+
+    let version = normalize_version(&release.version);
+    let version_dir = work_dir.join(format!("kilocode-sidecar-{version}"));
+    let entry_path = version_dir.join(SIDECAR_ENTRY);
+
+    if fs::metadata(&entry_path).is_err() {
+        let asset_name = asset_name_for_platform(version)?;
+        let asset = release
+            .assets
+            .iter()
+            .find(|asset| asset.name == asset_name)
+            .ok_or_else(|| {
+                format!(
+                    "no sidecar asset named {asset_name} in release {}",
+                    release.version
+                )
+            })?;
+
+        zed::set_language_server_installation_status(
+            language_server_id,
+            &zed::LanguageServerInstallationStatus::Downloading,
+        );
+        zed::download_file(
+            &asset.download_url,
+            &version_dir.to_string_lossy(),
+            zed::DownloadedFileType::GzipTar,
+        )?;
+        // server.js is launched as `node server.js`, so it never needs its
+        // own execute bit; no make_file_executable call here.
+    }
+
+    Ok(entry_path.to_string_lossy().into_owned())
+}
+
+/// Strips a GitHub release tag's leading `v`, if any, so a version is never
+/// accidentally `v`-prefixed twice when rebuilt into `kilocode-sidecar-v...`.
+fn normalize_version(version: &str) -> &str {
+    version.strip_prefix('v').unwrap_or(version)
+}
+
+/// Builds the release asset name for the host OS and architecture, e.g.
+/// `kilocode-sidecar-v0.1.0-linux-x86_64.tar.gz`. `version` must already be
+/// normalized (no leading `v`); see [`normalize_version`].
+fn asset_name_for_platform(version: &str) -> Result<String> {
+    let (os, arch) = zed::current_platform();
+    asset_name(version, os, arch)
+}
+
+fn asset_name(version: &str, os: zed::Os, arch: zed::Architecture) -> Result<String> {
+    let os = match os {
+        zed::Os::Mac => "darwin",
+        zed::Os::Linux => "linux",
+        zed::Os::Windows => "windows",
+    };
+    let arch = match arch {
+        zed::Architecture::Aarch64 => "aarch64",
+        zed::Architecture::X8664 => "x86_64",
+        zed::Architecture::X86 => return Err("unsupported architecture: x86".to_string()),
+    };
+
+    Ok(format!("kilocode-sidecar-v{version}-{os}-{arch}.tar.gz"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_asset_name_for_supported_platforms() {
+        assert_eq!(
+            asset_name("0.1.0", zed::Os::Linux, zed::Architecture::X8664).unwrap(),
+            "kilocode-sidecar-v0.1.0-linux-x86_64.tar.gz"
+        );
+        assert_eq!(
+            asset_name("0.1.0", zed::Os::Mac, zed::Architecture::Aarch64).unwrap(),
+            "kilocode-sidecar-v0.1.0-darwin-aarch64.tar.gz"
+        );
+        assert_eq!(
+            asset_name("0.1.0", zed::Os::Windows, zed::Architecture::X8664).unwrap(),
+            "kilocode-sidecar-v0.1.0-windows-x86_64.tar.gz"
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_architecture() {
+        assert!(asset_name("0.1.0", zed::Os::Linux, zed::Architecture::X86).is_err());
+    }
+
+    #[test]
+    fn normalizes_v_prefixed_release_tags() {
+        // GitHub (and kilocode's) release tags are normally v-prefixed, e.g.
+        // "v0.1.0". Without normalizing first, asset_name would double up
+        // the prefix into "kilocode-sidecar-vv0.1.0-...", which never
+        // matches a real release asset.
+        assert_eq!(normalize_version("v0.1.0"), "0.1.0");
+        assert_eq!(normalize_version("0.1.0"), "0.1.0");
+
+        let version = normalize_version("v0.1.0");
+        assert_eq!(
+            asset_name(version, zed::Os::Linux, zed::Architecture::X8664).unwrap(),
+            "kilocode-sidecar-v0.1.0-linux-x86_64.tar.gz"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const PORT_ENV_VAR: &str = "KILOCODE_SIDECAR_PORT";
+const CONFIG_FILE: &str = "sidecar.conf";
+const DEFAULT_PORT: u16 = 3001;
+
+/// How many ports above the preferred one the sidecar should be willing to
+/// try if its first choice is taken. See [`PortConfig`].
+const PORT_SCAN_RANGE: u16 = 20;
+
+/// The port the sidecar should try first, plus how much room it has to pick
+/// a different one if that port is already in use.
+///
+/// Automatic free-port selection can't be done by the extension itself: a
+/// wasm extension has no socket access to probe with (binding a listener
+/// and dropping it, as a host process would, isn't available here). So
+/// rather than silently passing a possibly-busy port and letting the
+/// launch fail, the resolved port comes with an explicit `scan_range`: the
+/// sidecar (a real host process, started on the host via the returned
+/// `zed::Command`) is expected to bind the first free port in
+/// `[port, port + scan_range]` and is the one actually responsible for
+/// resolving the collision this request is about.
+pub struct PortConfig {
+    pub port: u16,
+    pub scan_range: u16,
+}
+
+/// Resolves which port the sidecar should try first: the [`PORT_ENV_VAR`]
+/// environment variable takes precedence, then a `port = ...` line in
+/// `work_dir/sidecar.conf`, falling back to [`DEFAULT_PORT`].
+pub fn resolve_port(work_dir: &Path) -> PortConfig {
+    let port = env::var(PORT_ENV_VAR)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .or_else(|| preferred_port_from_file(work_dir))
+        .unwrap_or(DEFAULT_PORT);
+
+    PortConfig {
+        port,
+        scan_range: PORT_SCAN_RANGE,
+    }
+}
+
+fn preferred_port_from_file(work_dir: &Path) -> Option<u16> {
+    let contents = fs::read_to_string(work_dir.join(CONFIG_FILE)).ok()?;
+    contents.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        if key.trim() != "port" {
+            return None;
+        }
+        value.trim().parse().ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("kilocode-config-test-{name}"));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn parses_port_from_config_file() {
+        let dir = scratch_dir("parses-port");
+        fs::write(dir.join(CONFIG_FILE), "port = 4100\n").unwrap();
+
+        assert_eq!(preferred_port_from_file(&dir), Some(4100));
+    }
+
+    #[test]
+    fn ignores_unrelated_keys() {
+        let dir = scratch_dir("ignores-keys");
+        fs::write(dir.join(CONFIG_FILE), "host = 127.0.0.1\n").unwrap();
+
+        assert_eq!(preferred_port_from_file(&dir), None);
+    }
+
+    #[test]
+    fn missing_config_file_yields_none() {
+        let dir = scratch_dir("missing-file");
+
+        assert_eq!(preferred_port_from_file(&dir), None);
+    }
+}
@@ -1,3 +1,8 @@
+mod bootstrap;
+mod config;
+
+use std::path::PathBuf;
+
 use zed_extension_api::{self as zed, Result};
 
 struct KilocodeExtension;
@@ -6,6 +11,10 @@ impl KilocodeExtension {
     fn new() -> Self {
         Self
     }
+
+    fn work_dir() -> PathBuf {
+        PathBuf::from(".kilocode")
+    }
 }
 
 impl zed::Extension for KilocodeExtension {
@@ -13,21 +22,42 @@ impl zed::Extension for KilocodeExtension {
         Self::new()
     }
 
+    /// Zed calls this once per `language_server_id` and owns the resulting
+    /// process itself, so there is no in-extension equivalent of "only one
+    /// launch in flight at a time" to build: Zed's own per-id language
+    /// server lifecycle already guarantees a single instance.
+    ///
+    /// Health polling and restart-on-crash supervision are likewise Zed's
+    /// job once it owns the process from the `Command` returned below; a
+    /// wasm extension has no background thread to run its own poll loop
+    /// on. Zed already restarts a language server process that exits.
     fn language_server_command(
         &mut self,
-        _language_server_id: &zed::LanguageServerId,
-        _worktree: &zed::Worktree,
+        language_server_id: &zed::LanguageServerId,
+        worktree: &zed::Worktree,
     ) -> Result<zed::Command> {
-        // Return a simple echo command that shows the extension is loaded
-        // The actual AI functionality is provided by the sidecar at http://localhost:3001
+        let node_path = worktree
+            .which("node")
+            .unwrap_or_else(|| "node".to_string());
+        let work_dir = Self::work_dir();
+        let server_js = bootstrap::ensure_sidecar_bundle(language_server_id, &work_dir)?;
+        let port = config::resolve_port(&work_dir);
+
+        let mut env = worktree.shell_env();
+        env.push(("KILOCODE_WORKSPACE_ROOT".to_string(), worktree.root_path()));
+
         Ok(zed::Command {
-            command: "echo".to_string(),
+            command: node_path,
             args: vec![
-                "Kilocode AI Extension Loaded - Sidecar at http://localhost:3001".to_string()
+                server_js,
+                "--port".to_string(),
+                port.port.to_string(),
+                "--port-range".to_string(),
+                port.scan_range.to_string(),
             ],
-            env: Default::default(),
+            env,
         })
     }
 }
 
-zed::register_extension!(KilocodeExtension);
\ No newline at end of file
+zed::register_extension!(KilocodeExtension);